@@ -0,0 +1,222 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Honggfuzz target that pushes arbitrary `SuiSystemStateInnerV1`/`V2` fixtures
+//! through the indexer's epoch pipelines and back out of GraphQL.
+//!
+//! Each iteration generates a genesis system state whose `safe_mode_*` fields
+//! are fuzzer-driven, bootstraps an [`OffchainCluster`] with it, writes the
+//! genesis checkpoint and asserts that (a) the `kv_epoch_starts`/`kv_epoch_ends`
+//! pipelines never panic and (b) the `epoch { safeMode { gasSummary { .. } } }`
+//! response matches the balances that were injected. Any divergence is a crash.
+//!
+//! The case is bootstrapped afresh per iteration (mirroring the static
+//! `graphql_epoch_tests`), so each input drives an independent, valid epoch-0
+//! transition; the cluster is always torn down before the next input.
+//!
+//! Run with `cargo hfuzz run graphql_epoch`.
+
+use std::time::Duration;
+
+use arbitrary::{Arbitrary, Unstructured};
+use honggfuzz::fuzz;
+use serde::Deserialize;
+use tokio::runtime::Runtime;
+
+use sui_indexer_alt::config::{IndexerConfig, PipelineLayer};
+use sui_indexer_alt::{mock, BootstrapGenesis};
+use sui_indexer_alt_e2e_tests::{
+    local_ingestion_client_args, write_checkpoint, OffchainCluster, OffchainClusterConfig,
+};
+use sui_types::balance::Balance;
+use sui_types::sui_system_state::sui_system_state_inner_v1::SuiSystemStateInnerV1;
+use sui_types::sui_system_state::sui_system_state_inner_v2::SuiSystemStateInnerV2;
+use sui_types::sui_system_state::SuiSystemState;
+use sui_types::test_checkpoint_data_builder::{AdvanceEpochConfig, TestCheckpointDataBuilder};
+
+const SAFE_MODE_QUERY: &str = "query {
+        epoch(epochId: 0) {
+            safeMode {
+                enabled
+                gasSummary {
+                    computationCost
+                    storageCost
+                    storageRebate
+                    nonRefundableStorageFee
+                }
+            }
+        }
+    }";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SafeModeEpoch {
+    safe_mode: SafeMode,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SafeMode {
+    enabled: bool,
+    gas_summary: GasSummary,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GasSummary {
+    computation_cost: u64,
+    storage_cost: u64,
+    storage_rebate: u64,
+    non_refundable_storage_fee: u64,
+}
+
+/// Expected safe-mode values carried alongside the generated state, so the
+/// harness can compare the queried response without re-reading the enum.
+struct Expected {
+    enabled: bool,
+    computation_cost: u64,
+    storage_cost: u64,
+    storage_rebate: u64,
+    non_refundable_storage_fee: u64,
+}
+
+/// Build a genesis state with fuzzer-driven safe-mode fields. Everything else
+/// stays at the mock defaults (`epoch` is `0`, matching the `epoch(epochId: 0)`
+/// the harness queries); balances are built from `u64` so they cannot exceed
+/// what bcs and the GraphQL gas summary can represent.
+fn generate(u: &mut Unstructured<'_>) -> arbitrary::Result<(SuiSystemState, Expected)> {
+    let enabled = bool::arbitrary(u)?;
+    let computation_cost = u64::arbitrary(u)?;
+    let storage_cost = u64::arbitrary(u)?;
+    let storage_rebate = u64::arbitrary(u)?;
+    let non_refundable_storage_fee = u64::arbitrary(u)?;
+
+    let state = if bool::arbitrary(u)? {
+        SuiSystemState::V1(SuiSystemStateInnerV1 {
+            safe_mode: enabled,
+            safe_mode_computation_rewards: Balance::new(computation_cost),
+            safe_mode_storage_rewards: Balance::new(storage_cost),
+            safe_mode_storage_rebates: storage_rebate,
+            safe_mode_non_refundable_storage_fee: non_refundable_storage_fee,
+            ..mock::sui_system_state_inner_v1()
+        })
+    } else {
+        SuiSystemState::V2(SuiSystemStateInnerV2 {
+            safe_mode: enabled,
+            safe_mode_computation_rewards: Balance::new(computation_cost),
+            safe_mode_storage_rewards: Balance::new(storage_cost),
+            safe_mode_storage_rebates: storage_rebate,
+            safe_mode_non_refundable_storage_fee: non_refundable_storage_fee,
+            ..mock::sui_system_state_inner_v2()
+        })
+    };
+
+    let expected = Expected {
+        enabled,
+        computation_cost,
+        storage_cost,
+        storage_rebate,
+        non_refundable_storage_fee,
+    };
+
+    Ok((state, expected))
+}
+
+/// Fetch the safe-mode summary the indexer served back for epoch 0.
+async fn query_safe_mode(offchain: &OffchainCluster) -> anyhow::Result<SafeMode> {
+    offchain.wait_for_graphql(0, Duration::from_secs(10)).await?;
+
+    #[derive(Deserialize)]
+    struct Data {
+        epoch: SafeModeEpoch,
+    }
+
+    let data: Data = offchain.query_graphql(SAFE_MODE_QUERY).await?;
+    Ok(data.epoch.safe_mode)
+}
+
+async fn drive(state: SuiSystemState, expected: Expected) -> anyhow::Result<()> {
+    let (client_args, temp_dir) = local_ingestion_client_args();
+
+    // Inject the fuzzed state both as bootstrap genesis and as the advance-epoch
+    // output objects, exactly as the static tests do, so `epoch(epochId: 0)`
+    // reads the same values regardless of which source it resolves.
+    let offchain = OffchainCluster::new(
+        client_args,
+        OffchainClusterConfig {
+            indexer_config: IndexerConfig {
+                pipeline: PipelineLayer {
+                    cp_sequence_numbers: Some(Default::default()),
+                    kv_epoch_ends: Some(Default::default()),
+                    kv_epoch_starts: Some(Default::default()),
+                    ..Default::default()
+                },
+                ..IndexerConfig::default()
+            },
+            bootstrap_genesis: Some(BootstrapGenesis {
+                stored_genesis: mock::stored_genesis(),
+                sui_system_state: state.clone(),
+            }),
+            ..OffchainClusterConfig::default()
+        },
+    )
+    .await?;
+
+    let checkpoint_data = TestCheckpointDataBuilder::new(0).advance_epoch(AdvanceEpochConfig {
+        output_objects: mock::genesis_output_objects(state),
+        ..AdvanceEpochConfig::default()
+    });
+
+    // Run the fallible pipeline, then tear the cluster down before propagating
+    // or asserting, so no iteration leaks the cluster/temp-dir/DB connections.
+    let result = async {
+        write_checkpoint(temp_dir.path(), checkpoint_data).await?;
+        query_safe_mode(&offchain).await
+    }
+    .await;
+    offchain.stopped().await;
+
+    let SafeMode {
+        enabled,
+        gas_summary:
+            GasSummary {
+                computation_cost,
+                storage_cost,
+                storage_rebate,
+                non_refundable_storage_fee,
+            },
+    } = result?;
+
+    // Treat any divergence between what was injected and what the pipeline
+    // served back as a crash.
+    assert_eq!(enabled, expected.enabled);
+    assert_eq!(computation_cost, expected.computation_cost);
+    assert_eq!(storage_cost, expected.storage_cost);
+    assert_eq!(storage_rebate, expected.storage_rebate);
+    assert_eq!(
+        non_refundable_storage_fee,
+        expected.non_refundable_storage_fee
+    );
+
+    Ok(())
+}
+
+fn main() {
+    telemetry_subscribers::init_for_testing();
+
+    // Reuse a single runtime across iterations; the cluster is per-case.
+    let runtime = Runtime::new().expect("failed to build tokio runtime");
+
+    loop {
+        fuzz!(|data: &[u8]| {
+            let mut u = Unstructured::new(data);
+            let Ok((state, expected)) = generate(&mut u) else {
+                return;
+            };
+
+            runtime
+                .block_on(drive(state, expected))
+                .expect("indexer pipeline or GraphQL response diverged");
+        });
+    }
+}
@@ -184,8 +184,19 @@ async fn test_graphql<T: DeserializeOwned>(
     advance_epoch_config: AdvanceEpochConfig,
 ) -> anyhow::Result<T> {
     telemetry_subscribers::init_for_testing();
-    #[allow(unused)]
     let (client_args, temp_dir) = local_ingestion_client_args();
+
+    // Round-trip the system state through an on-disk snapshot so the tests
+    // assert against imported state rather than the hand-built struct, and the
+    // lossless-export claim is exercised (including the safe-mode balances).
+    let snapshot_path = temp_dir.path().join("genesis.snapshot");
+    mock::export_genesis_snapshot(&sui_system_state, &snapshot_path)?;
+    let bootstrap_genesis = mock::import_genesis_snapshot(
+        &snapshot_path,
+        mock::stored_genesis().initial_protocol_version,
+        sui_system_state.system_state_version(),
+    )?;
+
     let offchain = OffchainCluster::new(
         client_args,
         OffchainClusterConfig {
@@ -198,10 +209,7 @@ async fn test_graphql<T: DeserializeOwned>(
                 },
                 ..IndexerConfig::default()
             },
-            bootstrap_genesis: Some(BootstrapGenesis {
-                stored_genesis: mock::stored_genesis(),
-                sui_system_state,
-            }),
+            bootstrap_genesis: Some(bootstrap_genesis),
             ..OffchainClusterConfig::default()
         },
     )
@@ -1,12 +1,74 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
 use sui_indexer_alt_schema::checkpoints::StoredGenesis;
 use sui_types::balance::Balance;
 use sui_types::collection_types::VecMap;
 use sui_types::sui_system_state::sui_system_state_inner_v1::{
     StakeSubsidyV1, StorageFundV1, SuiSystemStateInnerV1, SystemParametersV1, ValidatorSetV1,
 };
+use sui_types::sui_system_state::SuiSystemState;
+
+use crate::BootstrapGenesis;
+
+// On-disk snapshot of the state needed to bootstrap an `OffchainCluster`,
+// carrying the protocol and system-state versions so `import_genesis_snapshot`
+// can reject a blob built for a version the caller isn't expecting.
+#[derive(Serialize, Deserialize)]
+struct GenesisSnapshot {
+    initial_protocol_version: u64,
+    system_state_version: u64,
+    stored_genesis: StoredGenesis,
+    sui_system_state: SuiSystemState,
+}
+
+pub fn export_genesis_snapshot(state: &SuiSystemState, out: &Path) -> anyhow::Result<()> {
+    let stored_genesis = stored_genesis();
+    let snapshot = GenesisSnapshot {
+        initial_protocol_version: stored_genesis.initial_protocol_version,
+        system_state_version: state.system_state_version(),
+        stored_genesis,
+        sui_system_state: state.clone(),
+    };
+
+    let bytes = bcs::to_bytes(&snapshot).context("serializing genesis snapshot")?;
+    fs::write(out, bytes).with_context(|| format!("writing genesis snapshot to {out:?}"))
+}
+
+pub fn import_genesis_snapshot(
+    path: &Path,
+    expected_initial_protocol_version: u64,
+    expected_system_state_version: u64,
+) -> anyhow::Result<BootstrapGenesis> {
+    let bytes = fs::read(path).with_context(|| format!("reading genesis snapshot from {path:?}"))?;
+    let snapshot: GenesisSnapshot =
+        bcs::from_bytes(&bytes).context("deserializing genesis snapshot")?;
+
+    if snapshot.initial_protocol_version != expected_initial_protocol_version {
+        bail!(
+            "genesis snapshot protocol version mismatch: snapshot {}, expected {}",
+            snapshot.initial_protocol_version,
+            expected_initial_protocol_version,
+        );
+    }
+    if snapshot.system_state_version != expected_system_state_version {
+        bail!(
+            "genesis snapshot system state version mismatch: snapshot {}, expected {}",
+            snapshot.system_state_version,
+            expected_system_state_version,
+        );
+    }
+
+    Ok(BootstrapGenesis {
+        stored_genesis: snapshot.stored_genesis,
+        sui_system_state: snapshot.sui_system_state,
+    })
+}
 
 pub fn stored_genesis() -> StoredGenesis {
     StoredGenesis {